@@ -1,11 +1,15 @@
 //! Ballots
 
 use rank::{SimpleRank, Rank};
+use number::Ratio;
 
 /// A ballot
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Ballot<R = SimpleRank> {
     name: Option<String>,
     ranks: Vec<R>,
+    weight: Ratio,
 }
 
 impl<R> Ballot<R> {
@@ -20,9 +24,79 @@ impl<R> Ballot<R> {
         Ballot {
             name: None,
             ranks: vec![Default::default(); candidates],
+            weight: Ratio::new(1, 1),
         }
     }
 
+    /// Set the weight (multiplicity) of the ballot.
+    ///
+    /// A ballot with weight `n` is counted as if `n` identical ballots had
+    /// been cast. Defaults to `1`. Use [`set_weight_ratio`] instead for a
+    /// fractional weight.
+    ///
+    /// ```
+    /// # use schulze::Nomination;
+    /// #
+    /// # let mut nomination = Nomination::new();
+    /// # nomination.nominate("Paul");
+    /// # let mut election = nomination.build();
+    /// # let mut ballot = election.new_ballot();
+    /// ballot.set_weight(10_000);
+    /// assert_eq!(ballot.weight(), 10_000);
+    /// ```
+    ///
+    /// [`set_weight_ratio`]: #method.set_weight_ratio
+    pub fn set_weight(&mut self, weight: u32) -> &mut Self {
+        self.weight = Ratio::new(i64::from(weight), 1);
+        self
+    }
+
+    /// Get the weight (multiplicity) of the ballot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weight is fractional (set via [`set_weight_ratio`]) or doesn't fit in a
+    /// `u32`; use [`weight_ratio`] instead to get the exact value in that case.
+    ///
+    /// [`set_weight_ratio`]: #method.set_weight_ratio
+    /// [`weight_ratio`]: #method.weight_ratio
+    pub fn weight(&self) -> u32 {
+        assert_eq!(
+            self.weight.denominator(), 1,
+            "ballot weight is fractional; use weight_ratio instead"
+        );
+        let numerator = self.weight.numerator();
+        assert!(
+            numerator >= 0 && numerator <= i64::from(u32::max_value()),
+            "ballot weight {} doesn't fit in a u32; use weight_ratio instead", numerator
+        );
+        numerator as u32
+    }
+
+    /// Set the weight (multiplicity) of the ballot to an exact fraction.
+    ///
+    /// Unlike [`set_weight`], this allows fractional multiplicities, e.g. giving a ballot half
+    /// the weight of a normal vote. Tally fractionally-weighted elections with
+    /// [`Election::result_as::<Ratio>`]: `i64` can't represent a fractional vote count, so an
+    /// `i64` tally panics wherever one results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weight` is negative; a ballot can't represent a negative number of voters.
+    ///
+    /// [`set_weight`]: #method.set_weight
+    /// [`Election::result_as::<Ratio>`]: ../election/struct.Election.html#method.result_as
+    pub fn set_weight_ratio(&mut self, weight: Ratio) -> &mut Self {
+        assert!(weight >= Ratio::new(0, 1), "ballot weight must not be negative");
+        self.weight = weight;
+        self
+    }
+
+    /// Get the weight (multiplicity) of the ballot as an exact fraction.
+    pub fn weight_ratio(&self) -> Ratio {
+        self.weight
+    }
+
     /// Set a name for the ballot (i.e. the voters name).
     ///
     /// ```
@@ -123,18 +197,18 @@ impl<R> Ballot<R> {
     /// assert!(ballot.get_rank(1) == &7.into());
     /// assert!(ballot.get_rank(2) == &3.into());
     /// ```
-    pub fn rank_all<T, I>(&mut self, ranks: T) -> &mut Self
+    pub fn rank_all<'a, T, I>(&mut self, ranks: T) -> &mut Self
     where
         R: Rank,
-        T: IntoIterator<Item = I>,
-        I: Into<R>,
+        T: IntoIterator<Item = &'a I>,
+        I: Copy + Into<R> + 'a,
     {
         let len = self.ranks.len();
         let mut src_iter = ranks.into_iter();
         let processed = self.ranks
             .iter_mut()
             .zip(src_iter.by_ref().take(len))
-            .map(|(src, dest)| { *src = dest.into(); })
+            .map(|(dest, &src)| { *dest = src.into(); })
             .count();
 
         assert!(
@@ -234,6 +308,43 @@ mod tests {
         assert_eq!(&shall, &is.as_slice());
     }
 
+    #[test]
+    fn weight_defaults_to_one_and_is_settable() {
+        let mut election = create_election();
+        let ballot = election.new_ballot();
+        assert_eq!(ballot.weight(), 1);
+
+        ballot.set_weight(10_000);
+        assert_eq!(ballot.weight(), 10_000);
+    }
+
+    #[test]
+    fn weight_ratio_defaults_to_one_and_is_settable() {
+        let mut election = create_election();
+        let ballot = election.new_ballot();
+        assert_eq!(ballot.weight_ratio(), Ratio::new(1, 1));
+
+        ballot.set_weight_ratio(Ratio::new(1, 2));
+        assert_eq!(ballot.weight_ratio(), Ratio::new(1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "ballot weight is fractional")]
+    fn weight_panics_on_fractional_weight() {
+        let mut election = create_election();
+        let ballot = election.new_ballot();
+        ballot.set_weight_ratio(Ratio::new(1, 2));
+        ballot.weight();
+    }
+
+    #[test]
+    #[should_panic(expected = "ballot weight must not be negative")]
+    fn set_weight_ratio_rejects_negative_weight() {
+        let mut election = create_election();
+        let ballot = election.new_ballot();
+        ballot.set_weight_ratio(Ratio::new(-1, 1));
+    }
+
     #[test]
     #[should_panic(expected = "number of ranks must match number of candidates exactly")]
     fn rank_all_too_few_ballots() {
@@ -0,0 +1,283 @@
+//! Import and export of elections in the BLT ballot file format
+//!
+//! BLT is a simple text format used across STV/Condorcet tallying tools to
+//! interchange ballot data. Layout:
+//!
+//! ```text
+//! <num candidates> <num seats>
+//! [<withdrawn candidate> ...]         // optional, negative candidate numbers
+//! <weight> <pref1> <pref2> ... 0      // one line per (group of) ballots
+//! ...
+//! 0                                   // terminates the list of ballots
+//! "<candidate name>"                  // one per candidate
+//! ...
+//! "<election title>"
+//! ```
+//!
+//! Preferences are 1-based candidate numbers in the order the voter ranked
+//! them; the first preference becomes the highest `SimpleRank`, candidates
+//! not mentioned on a ballot are left unranked. Candidates the voter ranked
+//! equally are joined with `=`, e.g. `1=2 3 0` ranks candidates 1 and 2
+//! equal first and candidate 3 second. A ballot's `weight` is imported as
+//! the `Ballot`'s weight rather than duplicating the ballot.
+//!
+//! Elections are imported and exported through [`Nomination::from_blt`] and
+//! [`Election::to_blt`].
+//!
+//! [`Nomination::from_blt`]: ../nomination/struct.Nomination.html#method.from_blt
+//! [`Election::to_blt`]: ../election/struct.Election.html#method.to_blt
+//!
+//! # Example
+//!
+//! ```
+//! use schulze::nomination::Nomination;
+//!
+//! let blt = "2 1\n\
+//!            1 1 2 0\n\
+//!            1 2 1 0\n\
+//!            0\n\
+//!            \"Alice\"\n\
+//!            \"Bob\"\n\
+//!            \"Example Election\"\n";
+//!
+//! let election = Nomination::from_blt(blt.as_bytes());
+//! assert_eq!(election.title(), Some("Example Election"));
+//! assert_eq!(election.ballots().len(), 2);
+//! ```
+
+use std::collections::HashSet;
+use std::io::{BufRead, Write};
+
+use election::Election;
+use nomination::Nomination;
+use rank::Rank;
+
+/// Read an `Election` from `reader` in the BLT ballot file format.
+///
+/// # Panics
+///
+/// Panics if `reader` does not contain well-formed BLT data.
+pub(crate) fn read<R: BufRead>(reader: R) -> Election {
+    let mut lines = reader
+        .lines()
+        .map(|line| line.expect("failed to read BLT data"));
+
+    let header = lines.next().expect("BLT data is missing the header line");
+    let mut header_parts = header.split_whitespace();
+    let num_candidates: usize = header_parts
+        .next()
+        .expect("BLT header is missing the number of candidates")
+        .parse()
+        .expect("number of candidates is not a number");
+    let _num_seats: usize = header_parts
+        .next()
+        .expect("BLT header is missing the number of seats")
+        .parse()
+        .expect("number of seats is not a number");
+
+    let mut withdrawn: HashSet<usize> = HashSet::new();
+    let mut ballots: Vec<(u32, Vec<Vec<usize>>)> = Vec::new();
+    loop {
+        let line = lines
+            .next()
+            .expect("BLT data ends before the ballot terminator");
+        let line = line.trim();
+        if line == "0" {
+            break;
+        }
+
+        let mut tokens = line.split_whitespace();
+
+        let first = tokens
+            .next()
+            .expect("ballot line is empty")
+            .parse::<i64>()
+            .expect("ballot line contains a non-numeric value");
+        if first < 0 {
+            // withdrawn candidate(s), given as 1-based candidate numbers negated;
+            // Schulze has no concept of withdrawal beyond simply not nominating
+            // them, so record the numbers and leave them out of `nomination` below.
+            withdrawn.insert((-first) as usize);
+            for token in tokens {
+                let num: i64 = token
+                    .parse()
+                    .expect("ballot line contains a non-numeric value");
+                if num == 0 {
+                    break;
+                }
+                withdrawn.insert((-num) as usize);
+            }
+            continue;
+        }
+
+        let weight = first as u32;
+        let preferences: Vec<Vec<usize>> = tokens
+            .take_while(|&t| t != "0")
+            .map(|group| {
+                group
+                    .split('=')
+                    .map(|n| n.parse::<usize>().expect("ballot line contains a non-numeric value") - 1)
+                    .collect()
+            })
+            .collect();
+        ballots.push((weight, preferences));
+    }
+
+    let names: Vec<String> = (0..num_candidates)
+        .map(|_| {
+            let line = lines.next().expect("BLT data is missing a candidate name");
+            unquote(line.trim())
+        })
+        .collect();
+
+    // Candidate numbers in `preferences` are 0-based positions into `names`; once withdrawn
+    // candidates are left out of `nomination`, later candidates shift down, so remap each
+    // surviving candidate to its new index and drop preferences for withdrawn candidates.
+    let mut nomination = Nomination::new();
+    let mut new_index = vec![None; names.len()];
+    let mut next_index = 0;
+    for (i, name) in names.iter().enumerate() {
+        if withdrawn.contains(&(i + 1)) {
+            continue;
+        }
+        nomination.nominate(name);
+        new_index[i] = Some(next_index);
+        next_index += 1;
+    }
+    let mut election = nomination.build();
+
+    if let Some(title) = lines.next() {
+        election.set_title(unquote(title.trim()));
+    }
+
+    for (weight, preferences) in ballots {
+        let ballot = election.new_ballot();
+        ballot.set_weight(weight);
+        for (rank, group) in preferences.iter().enumerate() {
+            for &candidate in group {
+                if let Some(index) = new_index[candidate] {
+                    ballot.rank(index, rank as u8);
+                }
+            }
+        }
+    }
+
+    election
+}
+
+/// Write `election` to `writer` in the BLT ballot file format.
+///
+/// Candidates a ballot ranks equally are written as a single `=`-joined
+/// group, e.g. `1=2`.
+pub(crate) fn write<W: Write>(election: &Election, mut writer: W) -> ::std::io::Result<()> {
+    writeln!(writer, "{} 1", election.candidates().len())?;
+
+    for ballot in election.ballots() {
+        let mut preferences: Vec<(usize, u8)> = ballot
+            .ranks()
+            .iter()
+            .enumerate()
+            .filter_map(|(id, rank)| rank.get_rank().map(|r| (id, r)))
+            .collect();
+        preferences.sort_by_key(|&(_, rank)| rank);
+
+        write!(writer, "{}", ballot.weight())?;
+        let mut preferences = preferences.into_iter().peekable();
+        while let Some((id, rank)) = preferences.next() {
+            let mut group = vec![id + 1];
+            while preferences.peek().map(|&(_, r)| r) == Some(rank) {
+                group.push(preferences.next().unwrap().0 + 1);
+            }
+            let group: Vec<String> = group.iter().map(ToString::to_string).collect();
+            write!(writer, " {}", group.join("="))?;
+        }
+        writeln!(writer, " 0")?;
+    }
+    writeln!(writer, "0")?;
+
+    for candidate in election.candidates() {
+        writeln!(writer, "\"{}\"", candidate.name())?;
+    }
+    writeln!(writer, "\"{}\"", election.title().unwrap_or(""))?;
+
+    Ok(())
+}
+
+fn unquote(s: &str) -> String {
+    s.trim_matches('"').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let blt = "3 1\n\
+                   1 1 2 3 0\n\
+                   2 2 3 1 0\n\
+                   0\n\
+                   \"Alice\"\n\
+                   \"Bob\"\n\
+                   \"Carol\"\n\
+                   \"Test Election\"\n";
+
+        let election = Nomination::from_blt(blt.as_bytes());
+        assert_eq!(
+            election.candidates().iter().map(|c| c.name()).collect::<Vec<_>>(),
+            &["Alice", "Bob", "Carol"]
+        );
+        assert_eq!(election.title(), Some("Test Election"));
+        assert_eq!(election.ballots().len(), 2);
+        assert_eq!(election.ballots()[1].weight(), 2);
+
+        let mut out = Vec::new();
+        write(&election, &mut out).unwrap();
+        let written = String::from_utf8(out).unwrap();
+
+        let round_tripped = Nomination::from_blt(written.as_bytes());
+        assert_eq!(
+            round_tripped.result().ranked_candidates().iter().map(|c| c.name()).collect::<Vec<_>>(),
+            election.result().ranked_candidates().iter().map(|c| c.name()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn equal_ranking_group_is_parsed_and_written_back() {
+        let blt = "3 1\n\
+                   1 1=2 3 0\n\
+                   0\n\
+                   \"Alice\"\n\
+                   \"Bob\"\n\
+                   \"Carol\"\n\
+                   \"Equal Ranking\"\n";
+
+        let election = Nomination::from_blt(blt.as_bytes());
+        let ballot = &election.ballots()[0];
+        assert_eq!(ballot.get_rank(0), ballot.get_rank(1));
+        assert!(ballot.get_rank(0) > ballot.get_rank(2));
+
+        let mut out = Vec::new();
+        write(&election, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "3 1\n1 1=2 3 0\n0\n\"Alice\"\n\"Bob\"\n\"Carol\"\n\"Equal Ranking\"\n");
+    }
+
+    #[test]
+    fn withdrawn_candidate_is_skipped() {
+        let blt = "2 1\n\
+                   -2\n\
+                   1 1 0\n\
+                   0\n\
+                   \"Alice\"\n\
+                   \"Bob\"\n\
+                   \"Title\"\n";
+
+        let election = Nomination::from_blt(blt.as_bytes());
+        assert_eq!(
+            election.candidates().iter().map(|c| c.name()).collect::<Vec<_>>(),
+            &["Alice"]
+        );
+        assert_eq!(election.ballots().len(), 1);
+        assert!(election.ballots()[0].get_rank(0) == &0.into());
+    }
+}
@@ -0,0 +1,394 @@
+//! Category constraints (guard/doom) on the Schulze ranking
+//!
+//! Some elections require a minimum and/or maximum number of seats to go to
+//! candidates in a given category (e.g. a regional or gender quota),
+//! similar to the CON-file constraints used by STV tallying tools.
+//! [`Categories`] tags candidates with category labels and [`seat`] applies
+//! a Grey-Fitzgerald-style guard/doom pass on top of an unconstrained
+//! Schulze ranking: walking the ranking top-down, a candidate who would
+//! push a category over its `max` is skipped ("doomed"); a candidate who
+//! is the last chance to reach a category's `min` is seated early
+//! ("guarded").
+//!
+//! [`Categories`]: struct.Categories.html
+//! [`seat`]: fn.seat.html
+
+use std::collections::HashMap;
+
+use Candidate;
+
+/// Tags candidates with category labels, consulted by [`seat`].
+///
+/// [`seat`]: fn.seat.html
+#[derive(Clone, Debug, Default)]
+pub struct Categories {
+    by_candidate: HashMap<String, Vec<String>>,
+}
+
+impl Categories {
+    /// Create an empty set of category tags.
+    pub fn new() -> Self {
+        Categories::default()
+    }
+
+    /// Tag `candidate` with `category`. A candidate may carry more than one category.
+    pub fn tag<T>(&mut self, candidate: &Candidate, category: T) -> &mut Self
+    where
+        T: ToString,
+    {
+        self.by_candidate
+            .entry(candidate.name().to_string())
+            .or_insert_with(Vec::new)
+            .push(category.to_string());
+        self
+    }
+
+    fn of(&self, candidate: &Candidate) -> &[String] {
+        self.by_candidate
+            .get(candidate.name())
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+/// A minimum/maximum seat quota for a category of candidates.
+#[derive(Clone, Debug)]
+pub struct Constraint {
+    category: String,
+    min: usize,
+    max: usize,
+}
+
+impl Constraint {
+    /// Require between `min` and `max` (inclusive) seated candidates tagged `category`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn new<T>(category: T, min: usize, max: usize) -> Self
+    where
+        T: ToString,
+    {
+        assert!(min <= max, "a constraint's min must not exceed its max");
+        Constraint { category: category.to_string(), min, max }
+    }
+
+    /// The category label this constraint applies to.
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// The minimum number of seats that must go to this category.
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    /// The maximum number of seats that may go to this category.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+}
+
+/// The outcome of applying constraints to one candidate in the ranking.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Decision {
+    /// Seated in the unconstrained order's next available position.
+    Seated,
+    /// Skipped because seating it would push a category over its `max`, or would use up a
+    /// seat that the still-unmet `min`s of other categories need.
+    Doomed,
+    /// Seated ahead of its unconstrained turn because skipping it would make some
+    /// category's `min` unreachable.
+    Guarded,
+}
+
+/// One candidate's outcome under [`seat`].
+///
+/// [`seat`]: fn.seat.html
+#[derive(Clone, Debug)]
+pub struct Seat {
+    candidate: Candidate,
+    decision: Decision,
+}
+
+impl Seat {
+    /// The candidate this decision was made about.
+    pub fn candidate(&self) -> &Candidate {
+        &self.candidate
+    }
+
+    /// The decision made about `candidate`.
+    pub fn decision(&self) -> Decision {
+        self.decision
+    }
+}
+
+/// Estimate how many seats are necessarily needed to meet every constraint's `min`, crediting
+/// a candidate tagged with several categories toward all of their `min`s at once instead of
+/// counting each category's `min` independently (which would double-count a candidate who can
+/// single-handedly satisfy more than one category).
+///
+/// This is a greedy heuristic, not an exact set-cover solver: at each step it spends whichever
+/// remaining candidate covers the most still-unmet minimums, on the assumption that candidates
+/// able to satisfy several constraints at once are the scarcest resource. It can undercount in
+/// pathological cases, but never overcounts, so it won't reject a ranking that can actually
+/// satisfy `constraints`.
+fn min_required_seats(ranking: &[Candidate], categories: &Categories, constraints: &[Constraint]) -> usize {
+    let mut remaining_min: HashMap<&str, usize> = constraints
+        .iter()
+        .filter(|c| c.min() > 0)
+        .map(|c| (c.category(), c.min()))
+        .collect();
+
+    let mut pool: Vec<&Candidate> = ranking.iter().collect();
+    let mut seats_needed = 0;
+
+    while remaining_min.values().any(|&need| need > 0) {
+        let best = pool
+            .iter()
+            .enumerate()
+            .map(|(i, candidate)| {
+                let covers = categories
+                    .of(candidate)
+                    .iter()
+                    .filter(|category| remaining_min.get(category.as_str()).map_or(false, |&need| need > 0))
+                    .count();
+                (i, covers)
+            })
+            .max_by_key(|&(_, covers)| covers);
+
+        match best {
+            Some((i, covers)) if covers > 0 => {
+                let candidate = pool.remove(i);
+                for category in categories.of(candidate) {
+                    if let Some(need) = remaining_min.get_mut(category.as_str()) {
+                        if *need > 0 {
+                            *need -= 1;
+                        }
+                    }
+                }
+                seats_needed += 1;
+            }
+            // No remaining candidate covers any unmet minimum, so those minimums can't be
+            // shared; charge them independently like the naive per-category sum would.
+            _ => {
+                seats_needed += remaining_min.values().sum::<usize>();
+                break;
+            }
+        }
+    }
+
+    seats_needed
+}
+
+/// Apply `constraints` to `ranking` (an unconstrained Schulze order, strongest first),
+/// selecting `seats` winners.
+///
+/// Walks `ranking` top-down, deciding each candidate in turn:
+///
+/// * `Guarded` — skipping the candidate would leave too few remaining candidates in one
+///   of its categories to still reach that category's `min`, so it is seated early.
+/// * `Doomed` — seating the candidate would push one of its categories over its `max`, or
+///   would use up a seat that the still-unmet `min`s of other categories need, so it is
+///   skipped.
+/// * `Seated` — neither applies; the candidate is seated in its unconstrained order.
+///
+/// # Panics
+///
+/// Panics if `seats` is greater than `ranking.len()`, if the seats needed to meet every
+/// category's `min` exceed `seats` (counted greedily, crediting a candidate tagged with several
+/// categories toward each of them), if a candidate is simultaneously required by a `min` and forbidden by a
+/// `max`, or if `constraints` otherwise can't be satisfied by `ranking`.
+pub fn seat(
+    ranking: &[Candidate],
+    seats: usize,
+    categories: &Categories,
+    constraints: &[Constraint],
+) -> Vec<Seat> {
+    assert!(
+        seats <= ranking.len(),
+        "not enough candidates ({}) to fill {} seats",
+        ranking.len(),
+        seats
+    );
+
+    let min_required = min_required_seats(ranking, categories, constraints);
+    assert!(
+        min_required <= seats,
+        "category minimums ({}) exceed the number of seats ({})",
+        min_required,
+        seats
+    );
+
+    let mut seated_count: HashMap<&str, usize> = HashMap::new();
+    let mut seats_filled = 0;
+    let mut result = Vec::with_capacity(ranking.len());
+
+    for (i, candidate) in ranking.iter().enumerate() {
+        if seats_filled == seats {
+            break;
+        }
+
+        let have = |category: &str| *seated_count.get(category).unwrap_or(&0);
+        let remaining_after = &ranking[i + 1..];
+        let candidate_categories = categories.of(candidate);
+
+        let breaches_max = constraints.iter().any(|constraint| {
+            candidate_categories.iter().any(|c| c == constraint.category())
+                && have(constraint.category()) >= constraint.max()
+        });
+
+        let essential_for_min = candidate_categories.iter().any(|category| {
+            let constraint = match constraints.iter().find(|c| c.category() == category) {
+                Some(constraint) => constraint,
+                None => return false,
+            };
+            let need = constraint.min().saturating_sub(have(category));
+            if need == 0 {
+                return false;
+            }
+            let available_if_skipped = remaining_after
+                .iter()
+                .filter(|other| categories.of(other).iter().any(|c| c == category))
+                .count();
+            available_if_skipped < need
+        });
+
+        assert!(
+            !(breaches_max && essential_for_min),
+            "constraints are unsatisfiable: {:?} is both required by a min and forbidden by a max",
+            candidate.name()
+        );
+
+        let seats_left_if_seated = seats - seats_filled - 1;
+        let unmet_need_if_seated: usize = constraints
+            .iter()
+            .map(|constraint| {
+                let contributes = candidate_categories.iter().any(|c| c == constraint.category());
+                let have_if_seated = have(constraint.category()) + contributes as usize;
+                constraint.min().saturating_sub(have_if_seated)
+            })
+            .sum();
+        let would_starve_other_minimums = unmet_need_if_seated > seats_left_if_seated;
+
+        // `essential_for_min` only means skipping the candidate *now* would be fatal; it says
+        // nothing about whether the candidate would have been seated anyway. A candidate whose
+        // unconstrained turn (position `i`) already falls within `seats` was never at risk of
+        // being skipped, so it's simply `Seated`, not `Guarded`.
+        let ahead_of_natural_turn = i >= seats;
+
+        let decision = if essential_for_min && ahead_of_natural_turn {
+            Decision::Guarded
+        } else if breaches_max || would_starve_other_minimums {
+            Decision::Doomed
+        } else {
+            Decision::Seated
+        };
+
+        if decision != Decision::Doomed {
+            for category in candidate_categories {
+                *seated_count.entry(category.as_str()).or_insert(0) += 1;
+            }
+            seats_filled += 1;
+        }
+
+        result.push(Seat { candidate: candidate.clone(), decision });
+    }
+
+    assert_eq!(
+        seats_filled, seats,
+        "constraints could not be satisfied by the given ranking"
+    );
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(names: &[&str]) -> Vec<Candidate> {
+        names.iter().map(|n| candidate(n)).collect()
+    }
+
+    fn candidate(name: &str) -> Candidate {
+        let mut nomination = ::nomination::Nomination::new();
+        nomination.nominate(name);
+        nomination.build().candidates()[0].clone()
+    }
+
+    #[test]
+    fn seats_top_n_when_unconstrained() {
+        let ranking = candidates(&["A", "B", "C"]);
+        let seats = seat(&ranking, 2, &Categories::new(), &[]);
+
+        assert_eq!(seats.len(), 2);
+        assert_eq!(seats[0].candidate().name(), "A");
+        assert_eq!(seats[0].decision(), Decision::Seated);
+        assert_eq!(seats[1].candidate().name(), "B");
+        assert_eq!(seats[1].decision(), Decision::Seated);
+    }
+
+    #[test]
+    fn doom_skips_candidate_that_would_exceed_the_maximum() {
+        let ranking = candidates(&["A", "B", "C"]);
+        let mut categories = Categories::new();
+        categories.tag(&ranking[0], "north");
+        categories.tag(&ranking[1], "north");
+
+        let constraints = [Constraint::new("north", 0, 1)];
+        let seats = seat(&ranking, 2, &categories, &constraints);
+
+        assert_eq!(seats[0].candidate().name(), "A");
+        assert_eq!(seats[0].decision(), Decision::Seated);
+        assert_eq!(seats[1].candidate().name(), "B");
+        assert_eq!(seats[1].decision(), Decision::Doomed);
+        assert_eq!(seats[2].candidate().name(), "C");
+        assert_eq!(seats[2].decision(), Decision::Seated);
+    }
+
+    #[test]
+    fn guard_forces_in_the_last_candidate_that_can_reach_a_minimum() {
+        // Only "C" carries the "north" tag, so B is doomed to free up the seat "north"
+        // needs and C is guarded in to meet the minimum.
+        let ranking = candidates(&["A", "B", "C"]);
+        let mut categories = Categories::new();
+        categories.tag(&ranking[2], "north");
+
+        let constraints = [Constraint::new("north", 1, 3)];
+        let seats = seat(&ranking, 2, &categories, &constraints);
+
+        assert_eq!(seats[0].candidate().name(), "A");
+        assert_eq!(seats[0].decision(), Decision::Seated);
+        assert_eq!(seats[1].candidate().name(), "B");
+        assert_eq!(seats[1].decision(), Decision::Doomed);
+        assert_eq!(seats[2].candidate().name(), "C");
+        assert_eq!(seats[2].decision(), Decision::Guarded);
+    }
+
+    #[test]
+    fn essential_candidate_within_its_natural_turn_is_seated_not_guarded() {
+        // "P" is the only "north" candidate, so it's essential for the category's minimum,
+        // but it's also first in the ranking: it was never at risk of being skipped, so it
+        // should come back `Seated`, not `Guarded`.
+        let ranking = candidates(&["P", "Q", "R"]);
+        let mut categories = Categories::new();
+        categories.tag(&ranking[0], "north");
+
+        let constraints = [Constraint::new("north", 1, 2)];
+        let seats = seat(&ranking, 2, &categories, &constraints);
+
+        assert_eq!(seats[0].candidate().name(), "P");
+        assert_eq!(seats[0].decision(), Decision::Seated);
+        assert_eq!(seats[1].candidate().name(), "Q");
+        assert_eq!(seats[1].decision(), Decision::Seated);
+    }
+
+    #[test]
+    #[should_panic(expected = "category minimums (3) exceed the number of seats (2)")]
+    fn panics_when_minimums_exceed_seats() {
+        let ranking = candidates(&["A", "B", "C"]);
+        let constraints = [Constraint::new("north", 3, 3)];
+        seat(&ranking, 2, &Categories::new(), &constraints);
+    }
+}
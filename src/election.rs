@@ -17,14 +17,20 @@
 
 use ballot::Ballot;
 use Candidate;
-use paths::Paths;
+use number::{Number, Ratio};
+use paths::{Paths, StrengthMeasure};
+use tie_break::{Direction, TieBreak};
 
-use std::cmp::{max, min};
+use std::io::{Result as IoResult, Write};
 
 /// Election
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Election {
     candidates: Vec<Candidate>,
     ballots: Vec<Ballot>,
+    strength_measure: StrengthMeasure,
+    tie_break: TieBreak,
+    title: Option<String>,
 }
 
 impl Election {
@@ -33,9 +39,58 @@ impl Election {
         Election {
             candidates,
             ballots: Vec::new(),
+            strength_measure: StrengthMeasure::default(),
+            tie_break: TieBreak::default(),
+            title: None,
         }
     }
 
+    /// Set the title of the election (e.g. for display or for BLT export).
+    pub fn set_title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: ToString,
+    {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Get the title of the election, if one has been set.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_ref().map(String::as_str)
+    }
+
+    /// Export this election to a writer using the BLT ballot file format.
+    ///
+    /// See the [`blt`] module for details on the format. To import a BLT file, use
+    /// [`Nomination::from_blt`].
+    ///
+    /// [`blt`]: ../blt/index.html
+    /// [`Nomination::from_blt`]: ../nomination/struct.Nomination.html#method.from_blt
+    pub fn to_blt<W: Write>(&self, writer: W) -> IoResult<()> {
+        ::blt::write(self, writer)
+    }
+
+    /// Select the `StrengthMeasure` used to compute the strongest paths.
+    ///
+    /// Defaults to [`StrengthMeasure::WinningVotes`].
+    ///
+    /// [`StrengthMeasure::WinningVotes`]: ../paths/enum.StrengthMeasure.html#variant.WinningVotes
+    pub fn set_strength_measure(&mut self, measure: StrengthMeasure) -> &mut Self {
+        self.strength_measure = measure;
+        self
+    }
+
+    /// Select the `TieBreak` strategy used to resolve ties in
+    /// `ElectionResult::ranked_candidates`.
+    ///
+    /// Defaults to [`TieBreak::None`].
+    ///
+    /// [`TieBreak::None`]: ../tie_break/enum.TieBreak.html#variant.None
+    pub fn set_tie_break(&mut self, tie_break: TieBreak) -> &mut Self {
+        self.tie_break = tie_break;
+        self
+    }
+
     /// Get all candidates
     ///
     /// ```
@@ -86,27 +141,119 @@ impl Election {
         &self.ballots
     }
 
-    /// Get result of election
+    /// Expand every weighted ballot into that many separate ballots of weight `1`.
     ///
-    /// See [`ElectionResult`] for details.
+    /// This is the inverse of compressing identical ballots into a single weighted one; useful
+    /// when downstream code expects one `Ballot` per voter. Counting results are unaffected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any ballot carries a fractional weight (see [`Ballot::set_weight_ratio`]),
+    /// since there is no way to split a fractional voter into whole ballots.
+    ///
+    /// [`Ballot::set_weight_ratio`]: ../ballot/struct.Ballot.html#method.set_weight_ratio
+    ///
+    /// ```
+    /// # use schulze::Nomination;
+    /// #
+    /// # let mut nomination = Nomination::new();
+    /// # nomination.nominate("Paul").nominate("Ivy");
+    /// # let mut election = nomination.build();
+    /// election.new_ballot().set_weight(3).rank_all(&[0, 1]);
+    /// assert_eq!(election.ballots().len(), 1);
+    ///
+    /// election.normalise();
+    /// assert_eq!(election.ballots().len(), 3);
+    /// assert!(election.ballots().iter().all(|b| b.weight() == 1));
+    /// ```
+    pub fn normalise(&mut self) {
+        let mut expanded = Vec::with_capacity(self.ballots.iter().map(|b| b.weight() as usize).sum());
+        for ballot in self.ballots.drain(..) {
+            for _ in 0..ballot.weight() {
+                let mut copy = ballot.clone();
+                copy.set_weight(1);
+                expanded.push(copy);
+            }
+        }
+        self.ballots = expanded;
+    }
+
+    /// Get result of election, with path strengths stored as `i64`.
+    ///
+    /// See [`ElectionResult`] for details. Use [`result_as`] instead to get exact strengths
+    /// under [`StrengthMeasure::Ratio`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if fractional ballot weights (see [`Ballot::set_weight_ratio`]) leave a link
+    /// strength that isn't a whole number, since `i64` can't represent a fractional vote count
+    /// exactly; use `result_as::<Ratio>()` instead.
     ///
     /// [`ElectionResult`]: struct.ElectionResult.html
+    /// [`result_as`]: #method.result_as
+    /// [`StrengthMeasure::Ratio`]: ../paths/enum.StrengthMeasure.html#variant.Ratio
+    /// [`Ballot::set_weight_ratio`]: ../ballot/struct.Ballot.html#method.set_weight_ratio
     pub fn result(&self) -> ElectionResult {
-        let paths = self.find_strongest_paths();
-        let mut ranking: Vec<_> = (0_usize..self.candidates.len()).collect();
-        Self::rank_candidates(&mut ranking[..], &paths);
+        self.result_as::<i64>()
+    }
+
+    /// Get result of election, with path strengths stored as `N`.
+    ///
+    /// `N` is usually `i64` (see [`result`], which is exactly `result_as::<i64>()`), but
+    /// passing [`number::Ratio`] instead gets a [`StrengthMeasure::Ratio`] result, and/or a
+    /// fractionally-weighted election (see [`Ballot::set_weight_ratio`]), without the precision
+    /// loss `i64` can't avoid.
+    ///
+    /// See [`ElectionResult`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fractional ballot weights leave a link strength that isn't a whole number and
+    /// `N` can't represent a fraction (e.g. `N = i64`).
+    ///
+    /// [`result`]: #method.result
+    /// [`ElectionResult`]: struct.ElectionResult.html
+    /// [`number::Ratio`]: ../number/struct.Ratio.html
+    /// [`StrengthMeasure::Ratio`]: ../paths/enum.StrengthMeasure.html#variant.Ratio
+    /// [`Ballot::set_weight_ratio`]: ../ballot/struct.Ballot.html#method.set_weight_ratio
+    pub fn result_as<N: Number>(&self) -> ElectionResult<N> {
+        let paths = self.find_strongest_paths::<N>();
+        let preferences = self.raw_preferences();
+
+        let (ranking, tie_broken) = match self.tie_break {
+            TieBreak::None => {
+                let mut ranking: Vec<_> = (0_usize..self.candidates.len()).collect();
+                Self::rank_candidates(&mut ranking[..], &paths);
+                let tie_broken = vec![false; ranking.len()];
+                (ranking, tie_broken)
+            }
+            TieBreak::Tbrc { ref order, direction } => {
+                let order = self.derive_tie_break_order(&preferences, order);
+                Self::rank_candidates_tbrc(&self.candidates, &paths, &order, direction)
+            }
+        };
         let ranked_candidates: Vec<_> = ranking
             .iter()
-            .map(|i| self.candidates[*i].clone())
+            .map(|&i| self.candidates[i].clone())
+            .collect();
+
+        let all: Vec<_> = (0_usize..self.candidates.len()).collect();
+        let potential_winners = Self::potential_winners(&all, &paths)
+            .into_iter()
+            .map(|i| self.candidates[i].clone())
             .collect();
 
         ElectionResult {
+            candidates: self.candidates.clone(),
+            preferences,
             ranked_candidates,
+            tie_broken,
+            potential_winners,
             paths,
         }
     }
 
-    fn rank_candidates(candidates: &mut [usize], paths: &Paths) {
+    fn rank_candidates<N: Number>(candidates: &mut [usize], paths: &Paths<N>) {
         for i in 0..candidates.len() {
             for j in i + 1..candidates.len() {
                 let c1 = candidates[i];
@@ -119,19 +266,141 @@ impl Election {
         }
     }
 
+    /// Subset of `candidates` (given as indices into `self.candidates`) that no other
+    /// candidate in the subset beats, i.e. the Schulze potential-winner set.
+    fn potential_winners<N: Number>(candidates: &[usize], paths: &Paths<N>) -> Vec<usize> {
+        candidates
+            .iter()
+            .cloned()
+            .filter(|&c| {
+                candidates
+                    .iter()
+                    .cloned()
+                    .all(|other| other == c || paths.path(other, c) <= paths.path(c, other))
+            })
+            .collect()
+    }
+
+    /// Subset of `candidates` (given as indices into `self.candidates`) that beats no other
+    /// candidate in the subset, i.e. the Schulze potential-loser set.
+    fn potential_losers<N: Number>(candidates: &[usize], paths: &Paths<N>) -> Vec<usize> {
+        candidates
+            .iter()
+            .cloned()
+            .filter(|&c| {
+                candidates
+                    .iter()
+                    .cloned()
+                    .all(|other| other == c || paths.path(c, other) <= paths.path(other, c))
+            })
+            .collect()
+    }
+
+    /// Derive a tie-breaking order of all candidates from the ballots, for use as the `order`
+    /// of a [`TieBreak::Tbrc`].
+    ///
+    /// Repeatedly takes the potential-winner set of the remaining candidates under the *raw*
+    /// pairwise preference counts (i.e. for each pair the direction with more ballots ranking
+    /// one over the other wins, same as [`ElectionResult::condorcet_winner`]'s relation), which
+    /// ranks each candidate above anyone they out-poll head-to-head. Whenever that set has more
+    /// than one member, or is empty — unlike the strongest-path matrix, the raw counts aren't
+    /// guaranteed transitive, so a Condorcet cycle among the remaining candidates can leave no
+    /// one undefeated — `fallback` (e.g. a randomly drawn tie-breaking ballot) picks which of the
+    /// remaining candidates comes first.
+    ///
+    /// [`TieBreak::Tbrc`]: ../tie_break/enum.TieBreak.html#variant.Tbrc
+    /// [`ElectionResult::condorcet_winner`]: struct.ElectionResult.html#method.condorcet_winner
+    fn derive_tie_break_order(&self, preferences: &Paths<Ratio>, fallback: &[Candidate]) -> Vec<Candidate> {
+        let mut remaining: Vec<usize> = (0..self.candidates.len()).collect();
+        let mut order = Vec::with_capacity(self.candidates.len());
+
+        while !remaining.is_empty() {
+            let winners = Self::potential_winners(&remaining, preferences);
+            let pool = if winners.is_empty() { &remaining } else { &winners };
+            let next = *pool
+                .iter()
+                .min_by_key(|&&c| {
+                    fallback
+                        .iter()
+                        .position(|o| *o == self.candidates[c])
+                        .unwrap_or_else(usize::max_value)
+                })
+                .expect("`remaining` is non-empty by the loop condition");
+            order.push(self.candidates[next].clone());
+            remaining.retain(|&c| c != next);
+        }
+
+        order
+    }
+
+    /// Rank candidates using the Tie-Breaking Ranking of Candidates (TBRC).
+    ///
+    /// In `Forwards` direction, repeatedly takes the potential-winner set among the remaining
+    /// candidates, ranking them top-down; in `Backwards` direction, repeatedly takes the
+    /// potential-loser set, ranking them bottom-up. Whenever that set has more than one member,
+    /// the one appearing earliest in `order` is picked, and the position is flagged as
+    /// tie-broken. Callers should pass [`Self::derive_tie_break_order`]'s result as `order` so
+    /// ties are resolved by the ballots before falling back to a supplied permutation.
+    ///
+    /// [`Self::derive_tie_break_order`]: #method.derive_tie_break_order
+    fn rank_candidates_tbrc<N: Number>(
+        candidates: &[Candidate],
+        paths: &Paths<N>,
+        order: &[Candidate],
+        direction: Direction,
+    ) -> (Vec<usize>, Vec<bool>) {
+        let mut remaining: Vec<usize> = (0..candidates.len()).collect();
+        let mut ranking = vec![0; candidates.len()];
+        let mut tie_broken = vec![false; candidates.len()];
+
+        let mut front = 0;
+        let mut back = candidates.len();
+        while !remaining.is_empty() {
+            let pool = match direction {
+                Direction::Forwards => Self::potential_winners(&remaining, paths),
+                Direction::Backwards => Self::potential_losers(&remaining, paths),
+            };
+            let next = *pool
+                .iter()
+                .min_by_key(|&&c| {
+                    order
+                        .iter()
+                        .position(|o| *o == candidates[c])
+                        .unwrap_or_else(usize::max_value)
+                })
+                .expect("potential-winner/-loser set is never empty");
+
+            let pos = match direction {
+                Direction::Forwards => {
+                    let pos = front;
+                    front += 1;
+                    pos
+                }
+                Direction::Backwards => {
+                    back -= 1;
+                    back
+                }
+            };
+            ranking[pos] = next;
+            tie_broken[pos] = pool.len() > 1;
+            remaining.retain(|&c| c != next);
+        }
+
+        (ranking, tie_broken)
+    }
+
     /// Find strongest paths for all candidates
     ///
     /// Search for the strongest paths using the Floyd-Warshall algorithm.
-    fn find_strongest_paths(&self) -> Paths {
+    fn find_strongest_paths<N: Number>(&self) -> Paths<N> {
         let mut paths = Paths::new(self.candidates.len());
 
         for i in 0..self.candidates.len() {
             for j in 0..self.candidates.len() {
                 if i != j {
                     let preferring_i = self.prefered_by(i, j);
-                    if preferring_i > self.prefered_by(j, i) {
-                        *paths.path_mut(i, j) = preferring_i;
-                    }
+                    let preferring_j = self.prefered_by(j, i);
+                    *paths.mut_path(i, j) = N::seed(self.strength_measure, preferring_i, preferring_j);
                 }
             }
         }
@@ -144,7 +413,8 @@ impl Election {
                             let j_k = paths.path(j, k);
                             let j_i = paths.path(j, i);
                             let i_k = paths.path(i, k);
-                            *paths.path_mut(j, k) = max(j_k, min(j_i, i_k));
+                            let via_i = if j_i <= i_k { j_i } else { i_k };
+                            *paths.mut_path(j, k) = if j_k >= via_i { j_k } else { via_i };
                         }
                     }
                 }
@@ -154,22 +424,62 @@ impl Election {
         paths
     }
 
-    /// Number of voters that prefer candidate `i` over `j`.
-    fn prefered_by(&self, i: usize, j: usize) -> u32 {
+    /// Raw pairwise preference counts, unaffected by the `StrengthMeasure` and not run through
+    /// Floyd-Warshall. Used by [`ElectionResult::condorcet_winner`] and
+    /// [`ElectionResult::smith_set`], which are defined directly in terms of pairwise
+    /// preferences rather than the strongest-path matrix.
+    ///
+    /// Stored as [`Ratio`] rather than `i64` so that fractionally-weighted ballots (see
+    /// [`Ballot::set_weight_ratio`]) are summed exactly.
+    ///
+    /// [`ElectionResult::condorcet_winner`]: struct.ElectionResult.html#method.condorcet_winner
+    /// [`ElectionResult::smith_set`]: struct.ElectionResult.html#method.smith_set
+    /// [`Ratio`]: ../number/struct.Ratio.html
+    /// [`Ballot::set_weight_ratio`]: ../ballot/struct.Ballot.html#method.set_weight_ratio
+    fn raw_preferences(&self) -> Paths<Ratio> {
+        let mut preferences = Paths::new(self.candidates.len());
+        for i in 0..self.candidates.len() {
+            for j in 0..self.candidates.len() {
+                if i != j {
+                    *preferences.mut_path(i, j) = self.prefered_by(i, j);
+                }
+            }
+        }
+        preferences
+    }
+
+    /// Number of voters that prefer candidate `i` over `j`, weighted by each ballot's
+    /// [`weight_ratio`].
+    ///
+    /// [`weight_ratio`]: ../ballot/struct.Ballot.html#method.weight_ratio
+    fn prefered_by(&self, i: usize, j: usize) -> Ratio {
         self.ballots
             .iter()
             .filter(|b| b.get_rank(i) > b.get_rank(j))
-            .count() as u32
+            .map(Ballot::weight_ratio)
+            .sum()
     }
 }
 
 /// Result of an `Election`
-pub struct ElectionResult {
+///
+/// Generic over the numeric type `N` used to store a path's strength (see [`Number`]),
+/// defaulting to `i64`; see [`Election::result_as`] for how to get an `ElectionResult<Ratio>`
+/// with exact strengths instead.
+///
+/// [`Number`]: ../number/trait.Number.html
+/// [`Election::result_as`]: struct.Election.html#method.result_as
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ElectionResult<N = i64> {
+    candidates: Vec<Candidate>,
     ranked_candidates: Vec<Candidate>,
-    paths: Paths,
+    tie_broken: Vec<bool>,
+    potential_winners: Vec<Candidate>,
+    preferences: Paths<Ratio>,
+    paths: Paths<N>,
 }
 
-impl ElectionResult {
+impl<N: Number> ElectionResult<N> {
     /// Candidates ranked according to the Schulze method.
     ///
     /// Cadidates are sorted by rank. Starting with the winner.
@@ -198,6 +508,28 @@ impl ElectionResult {
         &self.ranked_candidates
     }
 
+    /// Whether a tie had to be broken to place the candidate at the corresponding position of
+    /// `ranked_candidates()`.
+    ///
+    /// Only meaningful when a [`TieBreak::Tbrc`] strategy was used; otherwise every position is
+    /// `false`, since no tie-breaking decision was made.
+    ///
+    /// [`TieBreak::Tbrc`]: ../tie_break/enum.TieBreak.html#variant.Tbrc
+    pub fn tie_broken(&self) -> &[bool] {
+        &self.tie_broken
+    }
+
+    /// The Schulze potential-winner set: every candidate `X` such that no other candidate `Y`
+    /// beats `X` (i.e. there is no `Y` with `paths.path(Y, X) > paths.path(X, Y)`).
+    ///
+    /// Without a [`TieBreak`] strategy this set has more than one member exactly when
+    /// `ranked_candidates()` contains ties for first place.
+    ///
+    /// [`TieBreak`]: ../tie_break/enum.TieBreak.html
+    pub fn potential_winners(&self) -> &[Candidate] {
+        &self.potential_winners
+    }
+
     /// Get strongest paths between all candidates.
     ///
     /// ```
@@ -220,9 +552,54 @@ impl ElectionResult {
     ///     ]
     /// );
     /// ```
-    pub fn paths(&self) -> &Paths {
+    pub fn paths(&self) -> &Paths<N> {
         &self.paths
     }
+
+    /// The Condorcet winner, if one exists.
+    ///
+    /// The Condorcet winner is the candidate `X` preferred to every other candidate `Y` by more
+    /// voters than prefer `Y` to `X`, computed directly from the pairwise preference counts.
+    pub fn condorcet_winner(&self) -> Option<&Candidate> {
+        let n = self.candidates.len();
+        (0..n)
+            .find(|&x| {
+                (0..n).all(|y| y == x || self.preferences.path(x, y) > self.preferences.path(y, x))
+            })
+            .map(|x| &self.candidates[x])
+    }
+
+    /// The Smith set: the smallest non-empty set of candidates that collectively beat or tie
+    /// every candidate outside it.
+    ///
+    /// Computed as the transitive closure of the pairwise "beats-or-ties" relation, restricted
+    /// to candidates that can reach every other candidate.
+    pub fn smith_set(&self) -> Vec<&Candidate> {
+        let n = self.candidates.len();
+        let mut reach = vec![vec![false; n]; n];
+        for x in 0..n {
+            for y in 0..n {
+                if x == y || self.preferences.path(x, y) >= self.preferences.path(y, x) {
+                    reach[x][y] = true;
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if reach[i][k] && reach[k][j] {
+                        reach[i][j] = true;
+                    }
+                }
+            }
+        }
+
+        (0..n)
+            .filter(|&x| (0..n).all(|y| reach[x][y]))
+            .map(|x| &self.candidates[x])
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -244,6 +621,140 @@ mod tests {
         &[2, 1, 0],
     ];
 
+    #[test]
+    fn from_blt_and_to_blt_round_trip() {
+        let blt = "2 1\n\
+                   1 1 2 0\n\
+                   1 2 1 0\n\
+                   0\n\
+                   \"Alice\"\n\
+                   \"Bob\"\n\
+                   \"Example Election\"\n";
+
+        let election = Nomination::from_blt(blt.as_bytes());
+        assert_eq!(election.title(), Some("Example Election"));
+        assert_eq!(election.ballots().len(), 2);
+
+        let mut out = Vec::new();
+        election.to_blt(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), blt);
+    }
+
+    #[test]
+    fn normalise_preserves_result_but_expands_ballots() {
+        let mut nomination = Nomination::new();
+        nomination.nominate("A").nominate("B");
+        let mut election = nomination.build();
+
+        election.new_ballot().set_weight(3).rank_all(&[1, 0]);
+        election.new_ballot().rank_all(&[0, 1]);
+
+        let before = election.result();
+
+        election.normalise();
+        assert_eq!(election.ballots().len(), 4);
+        assert!(election.ballots().iter().all(|b| b.weight() == 1));
+
+        let after = election.result();
+        assert_eq!(
+            before.paths().iter().collect::<Vec<_>>(),
+            after.paths().iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn weighted_ballot_counts_as_many_identical_ballots() {
+        let mut nomination = Nomination::new();
+        nomination.nominate("A").nominate("B");
+        let mut election = nomination.build();
+
+        election.new_ballot().set_weight(3).rank_all(&[1, 0]);
+        election.new_ballot().rank_all(&[0, 1]);
+
+        let weighted = election.result();
+
+        let mut unweighted_election = nomination_ab();
+        for _ in 0..3 {
+            unweighted_election.new_ballot().rank_all(&[1, 0]);
+        }
+        unweighted_election.new_ballot().rank_all(&[0, 1]);
+        let unweighted = unweighted_election.result();
+
+        assert_eq!(
+            extract_paths(&weighted),
+            extract_paths(&unweighted),
+        );
+    }
+
+    #[test]
+    fn fractionally_weighted_ballot_counts_as_a_fraction_of_a_voter() {
+        let mut nomination = Nomination::new();
+        nomination.nominate("A").nominate("B");
+        let mut election = nomination.build();
+
+        // Two half-weighted ballots preferring A over B are equivalent to one full ballot.
+        election.new_ballot().set_weight_ratio(Ratio::new(1, 2)).rank_all(&[1, 0]);
+        election.new_ballot().set_weight_ratio(Ratio::new(1, 2)).rank_all(&[1, 0]);
+
+        let fractional = election.result_as::<Ratio>();
+
+        let mut whole_election = nomination_ab();
+        whole_election.new_ballot().rank_all(&[1, 0]);
+        let whole = whole_election.result_as::<Ratio>();
+
+        assert_eq!(
+            fractional.paths().iter().collect::<Vec<_>>(),
+            whole.paths().iter().collect::<Vec<_>>()
+        );
+    }
+
+    fn nomination_ab() -> Election {
+        let mut nomination = Nomination::new();
+        nomination.nominate("A").nominate("B");
+        nomination.build()
+    }
+
+    fn extract_paths(result: &ElectionResult) -> Vec<(usize, usize, i64)> {
+        result.paths().iter().collect()
+    }
+
+    #[test]
+    fn condorcet_winner_matches_top_of_ranking_and_smith_set() {
+        let mut nomination = Nomination::new();
+        nomination.nominate("A").nominate("B").nominate("C");
+        let mut election = nomination.build();
+
+        election.new_ballot().rank_all(&[0, 1, 2]);
+        election.new_ballot().rank_all(&[0, 1, 2]);
+        election.new_ballot().rank_all(&[2, 0, 1]);
+
+        let result = election.result();
+        let winner = result.condorcet_winner().expect("a Condorcet winner exists");
+        assert_eq!(winner.name(), "A");
+        assert_eq!(result.ranked_candidates()[0].name(), "A");
+
+        let smith_set: Vec<_> = result.smith_set().iter().map(|c| c.name()).collect();
+        assert_eq!(smith_set, &["A"]);
+    }
+
+    #[test]
+    fn no_condorcet_winner_in_a_cycle() {
+        let mut nomination = Nomination::new();
+        nomination.nominate("A").nominate("B").nominate("C");
+        let mut election = nomination.build();
+
+        election.new_ballot().rank_all(&[2, 1, 0]);
+        election.new_ballot().rank_all(&[1, 0, 2]);
+        election.new_ballot().rank_all(&[0, 2, 1]);
+
+        let result = election.result();
+        assert!(result.condorcet_winner().is_none());
+
+        let mut smith_set: Vec<_> = result.smith_set().iter().map(|c| c.name()).collect();
+        smith_set.sort();
+        assert_eq!(smith_set, &["A", "B", "C"]);
+    }
+
     #[test]
     fn ranking_no_ties() {
         let paths = paths_with_strengths(&[2, 4, 1, 2, 3, 1]);
@@ -263,14 +774,85 @@ mod tests {
         assert_possible_rankings(&paths, &[&[0, 1, 2], &[1, 0, 2], &[2, 0, 1], &[2, 1, 0]]);
     }
 
-    fn paths_with_strengths(ranks: &[u32; 6]) -> Paths {
+    #[test]
+    fn potential_winners_single() {
+        let paths = paths_with_strengths(&[2, 4, 1, 2, 3, 1]);
+        assert_eq!(Election::potential_winners(&[0, 1, 2], &paths), vec![0]);
+    }
+
+    #[test]
+    fn potential_winners_tie() {
+        let paths = paths_with_strengths(&[2, 3, 2, 3, 2, 2]);
+        let mut winners = Election::potential_winners(&[0, 1, 2], &paths);
+        winners.sort();
+        assert_eq!(winners, vec![0, 1]);
+    }
+
+    #[test]
+    fn election_set_tie_break_exposes_tie_broken_flags() {
+        let mut nomination = Nomination::new();
+        nomination.nominate("A").nominate("B").nominate("C");
+        let mut election = nomination.build();
+
+        // A and B tie, both beat C.
+        election.new_ballot().rank_all(&[0, 1, 2]);
+        election.new_ballot().rank_all(&[1, 0, 2]);
+
+        let order: Vec<_> = election.candidates().to_vec();
+        election.set_tie_break(TieBreak::Tbrc {
+            order,
+            direction: Direction::Forwards,
+        });
+
+        let result = election.result();
+        assert_eq!(result.tie_broken().len(), 3);
+        assert!(result.tie_broken()[0]);
+    }
+
+    #[test]
+    fn tbrc_forwards_picks_earliest_in_order() {
+        let candidates = vec![
+            Candidate { name: "A".to_string() },
+            Candidate { name: "B".to_string() },
+            Candidate { name: "C".to_string() },
+        ];
+        // A and B are tied, both beat C
+        let paths = paths_with_strengths(&[2, 3, 2, 3, 2, 2]);
+        let order = vec![candidates[1].clone(), candidates[0].clone(), candidates[2].clone()];
+
+        let (ranking, tie_broken) =
+            Election::rank_candidates_tbrc(&candidates, &paths, &order, Direction::Forwards);
+        assert_eq!(ranking, vec![1, 0, 2]);
+        assert_eq!(tie_broken, vec![true, false, false]);
+    }
+
+    #[test]
+    fn tbrc_backwards_picks_earliest_in_order_from_the_bottom() {
+        let candidates = vec![
+            Candidate { name: "A".to_string() },
+            Candidate { name: "B".to_string() },
+            Candidate { name: "C".to_string() },
+        ];
+        // A and B are tied, both beat C, so C is the sole potential loser and is placed last
+        // regardless of direction; A and B then tie for the remaining (bottom, in this case
+        // top) two positions.
+        let paths = paths_with_strengths(&[2, 3, 2, 3, 2, 2]);
+        let order = vec![candidates[1].clone(), candidates[0].clone(), candidates[2].clone()];
+
+        let (ranking, tie_broken) =
+            Election::rank_candidates_tbrc(&candidates, &paths, &order, Direction::Backwards);
+        assert_eq!(ranking, vec![0, 1, 2]);
+        assert_eq!(tie_broken, vec![false, true, false]);
+    }
+
+    fn paths_with_strengths(ranks: &[i64; 6]) -> Paths {
         let mut paths = Paths::new(3);
-        *paths.path_mut(0, 1) = ranks[0];
-        *paths.path_mut(0, 2) = ranks[1];
-        *paths.path_mut(1, 0) = ranks[2];
-        *paths.path_mut(1, 2) = ranks[3];
-        *paths.path_mut(2, 0) = ranks[4];
-        *paths.path_mut(2, 1) = ranks[5];
+        *paths.mut_path(0, 1) = ranks[0];
+        *paths.mut_path(0, 2) = ranks[1];
+        *paths.mut_path(1, 0) = ranks[2];
+        *paths.mut_path(1, 2) = ranks[3];
+        *paths.mut_path(2, 0) = ranks[4];
+        *paths.mut_path(2, 1) = ranks[5];
         paths
     }
 
@@ -307,6 +889,23 @@ mod tests {
             }
         }
 
-        b.iter(|| { election.find_strongest_paths(); });
+        b.iter(|| { election.find_strongest_paths::<i64>(); });
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn election_round_trips_through_serde_json() {
+        let mut election = nomination_ab();
+        election.set_title("Serde Round Trip");
+        election.new_ballot().rank_all(&[0, 1]);
+
+        let json = ::serde_json::to_string(&election).unwrap();
+        let restored: Election = ::serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.title(), election.title());
+        assert_eq!(
+            restored.result().ranked_candidates().iter().map(|c| c.name()).collect::<Vec<_>>(),
+            election.result().ranked_candidates().iter().map(|c| c.name()).collect::<Vec<_>>()
+        );
     }
 }
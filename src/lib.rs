@@ -40,15 +40,31 @@
 #![cfg_attr(feature="clippy", plugin(clippy))]
 #![cfg_attr(all(test, feature = "unstable"), feature(test))]
 
+// Enable the `serde` feature to (de)serialize `Candidate`, `Ballot`, `SimpleRank`, `Paths`,
+// `Election` and `ElectionResult`, e.g. to persist an in-progress election between processes.
+// A zero-copy `rkyv` backend is a natural follow-up but isn't implemented yet.
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde_derive;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
 pub mod ballot;
+pub mod blt;
+pub mod constraints;
 pub mod election;
 pub mod nomination;
+pub mod number;
 pub mod paths;
 pub mod rank;
+pub mod tie_break;
 
 pub use nomination::Nomination;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Candidate {
     name: String,
 }
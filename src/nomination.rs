@@ -14,11 +14,12 @@
 //!     .nominate("Ivy");
 //!
 //! // create election
-//! let election = nomination.election();
+//! let election = nomination.build();
 //! ```
 
+use blt;
 use election::Election;
-use rank::Rank;
+use std::io::BufRead;
 use Candidate;
 
 /// Nomination of candidates
@@ -53,29 +54,17 @@ impl Nomination {
     }
 
     /// Create election
-    pub fn election(self) -> Election {
+    pub fn build(self) -> Election {
         Election::new(self.candidates)
     }
 
-    /// Create election with custom `Rank`ing
+    /// Import an election from `reader` in the BLT ballot file format.
     ///
-    /// # Example
+    /// See the [`blt`] module for details on the format.
     ///
-    /// ```
-    /// extern crate schulze;
-    ///
-    /// use schulze::Nomination;
-    /// use schulze::rank::SimpleRank;
-    ///
-    /// let mut nomination = Nomination::new();
-    /// nomination
-    ///    .nominate("Lea")
-    ///    .nominate("Nora")
-    ///    .nominate("Zahra");
-    /// let mut election = nomination.election_with_ranking::<SimpleRank>();
-    /// ```
-    pub fn election_with_ranking<R: Rank>(self) -> Election<R> {
-        Election::new(self.candidates)
+    /// [`blt`]: ../blt/index.html
+    pub fn from_blt<R: BufRead>(reader: R) -> Election {
+        blt::read(reader)
     }
 }
 
@@ -90,7 +79,7 @@ mod tests {
             .nominate("Dianne Summer")
             .nominate("John Winter")
             .nominate("Ivy Spring");
-        let election = nomination.election();
+        let election = nomination.build();
 
         assert_eq!(
             election
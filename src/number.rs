@@ -0,0 +1,327 @@
+//! Numeric types usable as `Paths` strengths
+//!
+//! [`Paths`] is generic over the type used to store a link's strength so
+//! that users who need exact rational strengths (e.g. for the `Ratio`
+//! strength measure, or for elections with fractionally-weighted ballots,
+//! see [`Ballot::set_weight_ratio`]) aren't stuck with `i64`, which can
+//! represent neither exactly. [`Number`] is the bound required of that type; it is
+//! implemented for `i64` and for [`Ratio`], an exact-fraction numeric type.
+//!
+//! [`Paths`]: ../paths/struct.Paths.html
+//! [`Ballot::set_weight_ratio`]: ../ballot/struct.Ballot.html#method.set_weight_ratio
+
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Div};
+use std::iter::Sum;
+
+use paths::StrengthMeasure;
+
+/// A numeric type usable as the strength of a [`Paths`] link.
+///
+/// [`Paths`]: ../paths/struct.Paths.html
+pub trait Number: Copy + PartialOrd {
+    /// The value representing "no link" / the weakest possible strength.
+    fn weakest() -> Self;
+
+    /// Seed the strength of link `i -> j` under `measure`, given the (possibly fractional, if
+    /// ballots carry a [`Ratio`] weight) number of voters preferring `i` over `j`
+    /// (`preferring_i`) and `j` over `i` (`preferring_j`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `measure` cannot be represented exactly by this type, e.g.
+    /// `StrengthMeasure::Ratio` seeded into `i64` (use [`Ratio`] instead), or if the strength
+    /// being seeded (e.g. `preferring_i`, or the margin `preferring_i - preferring_j`) is
+    /// fractional and this type can't represent a fraction (e.g. `i64`).
+    ///
+    /// [`Ratio`]: struct.Ratio.html
+    fn seed(measure: StrengthMeasure, preferring_i: Ratio, preferring_j: Ratio) -> Self;
+}
+
+impl Number for i64 {
+    fn weakest() -> Self {
+        0
+    }
+
+    fn seed(measure: StrengthMeasure, preferring_i: Ratio, preferring_j: Ratio) -> Self {
+        match measure {
+            StrengthMeasure::WinningVotes => {
+                if preferring_i > preferring_j {
+                    whole(preferring_i)
+                } else {
+                    0
+                }
+            }
+            StrengthMeasure::Margin => whole(preferring_i - preferring_j),
+            StrengthMeasure::Ratio => panic!(
+                "`i64` can't represent `StrengthMeasure::Ratio` exactly; use `Ratio` instead"
+            ),
+        }
+    }
+}
+
+/// Convert an exact `Ratio` known to be a whole number into an `i64`.
+///
+/// # Panics
+///
+/// Panics if `ratio` is fractional, e.g. because a ballot was given a fractional weight that
+/// `i64` can't represent exactly.
+fn whole(ratio: Ratio) -> i64 {
+    assert_eq!(
+        ratio.denominator(), 1,
+        "`i64` can't represent the fractional weight {:?} exactly; use `Ratio` instead", ratio
+    );
+    ratio.numerator()
+}
+
+/// An exact rational number, used to represent the `Ratio` strength measure
+/// without the precision loss of fixed-point approximation.
+///
+/// Equality and ordering are computed by cross-multiplication (widened to
+/// `i128` to avoid overflow), so two `Ratio`s that denote the same value
+/// compare equal regardless of how they're represented.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ratio {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Ratio {
+    /// Create a new ratio `numerator / denominator`, reduced to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert_ne!(denominator, 0, "denominator must not be zero");
+        reduce(i128::from(numerator), i128::from(denominator))
+    }
+
+    /// The numerator of the ratio, in lowest terms.
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// The denominator of the ratio, in lowest terms. Always positive.
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+}
+
+impl Default for Ratio {
+    fn default() -> Self {
+        Ratio { numerator: 0, denominator: 1 }
+    }
+}
+
+impl PartialEq for Ratio {
+    fn eq(&self, other: &Ratio) -> bool {
+        i128::from(self.numerator) * i128::from(other.denominator)
+            == i128::from(other.numerator) * i128::from(self.denominator)
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Ratio) -> Option<Ordering> {
+        let left = i128::from(self.numerator) * i128::from(other.denominator);
+        let right = i128::from(other.numerator) * i128::from(self.denominator);
+        left.partial_cmp(&right)
+    }
+}
+
+impl Add for Ratio {
+    type Output = Ratio;
+
+    fn add(self, other: Ratio) -> Ratio {
+        let numerator = i128::from(self.numerator) * i128::from(other.denominator)
+            + i128::from(other.numerator) * i128::from(self.denominator);
+        let denominator = i128::from(self.denominator) * i128::from(other.denominator);
+        reduce(numerator, denominator)
+    }
+}
+
+impl Sub for Ratio {
+    type Output = Ratio;
+
+    fn sub(self, other: Ratio) -> Ratio {
+        let numerator = i128::from(self.numerator) * i128::from(other.denominator)
+            - i128::from(other.numerator) * i128::from(self.denominator);
+        let denominator = i128::from(self.denominator) * i128::from(other.denominator);
+        reduce(numerator, denominator)
+    }
+}
+
+impl Div for Ratio {
+    type Output = Ratio;
+
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn div(self, other: Ratio) -> Ratio {
+        assert_ne!(other.numerator, 0, "can't divide a `Ratio` by zero");
+        let numerator = i128::from(self.numerator) * i128::from(other.denominator);
+        let denominator = i128::from(self.denominator) * i128::from(other.numerator);
+        reduce(numerator, denominator)
+    }
+}
+
+impl Sum for Ratio {
+    fn sum<I: Iterator<Item = Ratio>>(iter: I) -> Ratio {
+        iter.fold(Ratio::default(), Add::add)
+    }
+}
+
+impl Number for Ratio {
+    fn weakest() -> Self {
+        Ratio::new(0, 1)
+    }
+
+    fn seed(measure: StrengthMeasure, preferring_i: Ratio, preferring_j: Ratio) -> Self {
+        match measure {
+            StrengthMeasure::WinningVotes => {
+                if preferring_i > preferring_j {
+                    preferring_i
+                } else {
+                    Ratio::weakest()
+                }
+            }
+            StrengthMeasure::Margin => preferring_i - preferring_j,
+            StrengthMeasure::Ratio => {
+                if preferring_i <= preferring_j {
+                    Ratio::weakest()
+                } else if preferring_j == Ratio::weakest() {
+                    Ratio::new(i64::MAX, 1)
+                } else {
+                    preferring_i / preferring_j
+                }
+            }
+        }
+    }
+}
+
+/// Reduce `numerator / denominator` to lowest terms with a positive denominator, widened to
+/// `i128` so intermediate products from [`Add`]/[`Sub`]/[`Div`] can't overflow before reduction.
+///
+/// # Panics
+///
+/// Panics if the reduced numerator or denominator no longer fits in `i64`.
+fn reduce(numerator: i128, denominator: i128) -> Ratio {
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+    let divisor = gcd(numerator.abs(), denominator);
+    let (numerator, denominator) = if divisor == 0 {
+        (numerator, denominator)
+    } else {
+        (numerator / divisor, denominator / divisor)
+    };
+    assert!(
+        numerator >= i128::from(i64::MIN) && numerator <= i128::from(i64::MAX),
+        "ratio arithmetic overflowed i64"
+    );
+    assert!(
+        denominator <= i128::from(i64::MAX),
+        "ratio arithmetic overflowed i64"
+    );
+    Ratio {
+        numerator: numerator as i64,
+        denominator: denominator as i64,
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_is_reduced_to_lowest_terms() {
+        let ratio = Ratio::new(10, 4);
+        assert_eq!(ratio.numerator(), 5);
+        assert_eq!(ratio.denominator(), 2);
+    }
+
+    #[test]
+    fn ratio_equality_and_ordering_ignore_representation() {
+        assert_eq!(Ratio::new(1, 2), Ratio::new(2, 4));
+        assert!(Ratio::new(3, 4) > Ratio::new(1, 2));
+        assert!(Ratio::new(1, 3) < Ratio::new(1, 2));
+    }
+
+    #[test]
+    fn ratio_does_not_collapse_close_but_distinct_fractions() {
+        // Under the old fixed-point `i64` approximation (scaled by 1_000_000) these two
+        // ratios rounded to the same value and compared equal; stored exactly, they don't.
+        assert_ne!(Ratio::new(1_000_001, 1_000_000), Ratio::new(3_000_004, 3_000_000));
+    }
+
+    #[test]
+    fn ratio_arithmetic_is_exact() {
+        assert_eq!(Ratio::new(1, 3) + Ratio::new(1, 6), Ratio::new(1, 2));
+        assert_eq!(Ratio::new(1, 2) - Ratio::new(1, 3), Ratio::new(1, 6));
+        assert_eq!(Ratio::new(2, 3) / Ratio::new(4, 9), Ratio::new(3, 2));
+    }
+
+    #[test]
+    fn i64_seed_matches_the_plain_measures() {
+        let (five, three) = (Ratio::new(5, 1), Ratio::new(3, 1));
+        assert_eq!(i64::seed(StrengthMeasure::WinningVotes, five, three), 5);
+        assert_eq!(i64::seed(StrengthMeasure::WinningVotes, three, five), 0);
+        assert_eq!(i64::seed(StrengthMeasure::Margin, five, three), 2);
+        assert_eq!(i64::seed(StrengthMeasure::Margin, three, five), -2);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't represent `StrengthMeasure::Ratio` exactly")]
+    fn i64_seed_rejects_ratio_measure() {
+        i64::seed(StrengthMeasure::Ratio, Ratio::new(5, 1), Ratio::new(3, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't represent the fractional weight")]
+    fn i64_seed_rejects_fractional_preference_counts() {
+        i64::seed(StrengthMeasure::WinningVotes, Ratio::new(5, 2), Ratio::new(1, 1));
+    }
+
+    #[test]
+    fn ratio_seed_is_exact() {
+        let (ten, four, three, five, zero) = (
+            Ratio::new(10, 1),
+            Ratio::new(4, 1),
+            Ratio::new(3, 1),
+            Ratio::new(5, 1),
+            Ratio::weakest(),
+        );
+        assert_eq!(Ratio::seed(StrengthMeasure::Ratio, ten, four), Ratio::new(5, 2));
+        assert_eq!(Ratio::seed(StrengthMeasure::Ratio, three, five), Ratio::weakest());
+        assert_eq!(Ratio::seed(StrengthMeasure::Ratio, ten, zero), Ratio::new(i64::MAX, 1));
+    }
+
+    #[test]
+    fn ratio_seed_also_supports_winning_votes_and_margin() {
+        let (five, three) = (Ratio::new(5, 1), Ratio::new(3, 1));
+        assert_eq!(Ratio::seed(StrengthMeasure::WinningVotes, five, three), Ratio::new(5, 1));
+        assert_eq!(Ratio::seed(StrengthMeasure::WinningVotes, three, five), Ratio::weakest());
+        assert_eq!(Ratio::seed(StrengthMeasure::Margin, five, three), Ratio::new(2, 1));
+    }
+
+    #[test]
+    fn ratio_seed_supports_fractional_preference_counts() {
+        // A half-weighted ballot preferring i over j contributes 0.5 "voters" to preferring_i.
+        let half = Ratio::new(1, 2);
+        assert_eq!(
+            Ratio::seed(StrengthMeasure::WinningVotes, half, Ratio::weakest()),
+            half
+        );
+    }
+}
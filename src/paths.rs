@@ -24,29 +24,77 @@
 use std::iter::FusedIterator;
 use std::slice;
 
+use number::Number;
+
+/// Measure used to determine the strength of a link between two candidates.
+///
+/// The Schulze method is agnostic to how the strength of a pairwise link is
+/// derived from the ballots; these are the three variants commonly found in
+/// the literature. Selecting a different measure can change the winner.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StrengthMeasure {
+    /// Strength of `i -> j` is the number of voters preferring `i` over `j`,
+    /// but only if that number is greater than the number preferring `j`
+    /// over `i`; otherwise there is no link. This is the default and the
+    /// most commonly used measure.
+    WinningVotes,
+    /// Strength of `i -> j` is the margin, i.e. the number of voters
+    /// preferring `i` over `j` minus the number preferring `j` over `i`.
+    /// Unlike `WinningVotes`, a tie still produces a (zero-strength) link.
+    Margin,
+    /// Strength of `i -> j` is the ratio of voters preferring `i` over `j`
+    /// to voters preferring `j` over `i`.
+    ///
+    /// `i64`'s [`Number::seed`] can't represent a ratio exactly and panics
+    /// if asked to; compute a [`paths::Paths`] over [`number::Ratio`]
+    /// instead (e.g. via `Election::result_as::<Ratio>()`) to get the exact
+    /// fraction.
+    ///
+    /// [`Number::seed`]: ../number/trait.Number.html#tymethod.seed
+    /// [`paths::Paths`]: struct.Paths.html
+    /// [`number::Ratio`]: ../number/struct.Ratio.html
+    Ratio,
+}
+
+impl Default for StrengthMeasure {
+    fn default() -> Self {
+        StrengthMeasure::WinningVotes
+    }
+}
+
 /// Strengths of the strongest paths
-pub struct Paths {
+///
+/// Generic over the numeric type `N` used to store a strength (see
+/// [`Number`]), defaulting to `i64`. This is what lets `Ratio`-measured
+/// strengths be stored exactly instead of through a lossy fixed-point
+/// approximation.
+///
+/// [`Number`]: ../number/trait.Number.html
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Paths<N = i64> {
     candidates: usize,
-    paths: Vec<u32>,
+    paths: Vec<N>,
 }
 
-impl Paths {
+impl<N: Number> Paths<N> {
     /// Create storage for holding the strengths of strongest paths for N candidates
     pub(crate) fn new(candidates: usize) -> Self {
         Paths {
             candidates,
-            paths: vec![0; candidates * candidates],
+            paths: vec![N::weakest(); candidates * candidates],
         }
     }
 
     /// Return the strength of the strongest path between candidate `to` and candidate `from`
     ///
-    /// Returns the total number of voters that prefer candidate `to` over candidate `from`.
+    /// Returns the total number of voters that prefer candidate `to` over candidate `from`,
+    /// as interpreted by the `StrengthMeasure` the path matrix was computed with.
     ///
     /// # Panics
     ///
     /// Panics if `to == from` and if `to` or `from` is out of range.
-    pub fn path(&self, to: usize, from: usize) -> u32 {
+    pub fn path(&self, to: usize, from: usize) -> N {
         assert_ne!(to, from, "candidates have no preference to themselves");
         self.paths[to * self.candidates + from]
     }
@@ -59,7 +107,7 @@ impl Paths {
     /// # Panics
     ///
     /// Panics if `to == from` and if `to` or `from` is out of range.
-    pub(crate) fn mut_path(&mut self, to: usize, from: usize) -> &mut u32 {
+    pub(crate) fn mut_path(&mut self, to: usize, from: usize) -> &mut N {
         assert_ne!(to, from, "candidates have no preference to themselves");
         &mut self.paths[to * self.candidates + from]
     }
@@ -74,21 +122,21 @@ impl Paths {
     /// instance, when there are three candidates, the sorting looks like this:
     /// `(0, 1, _)`, `(0, 2, _)`, `(1, 0, _)`, `(1, 2, _)`, `(2, 0, _)` and
     /// then `(2, 1, _)`.
-    pub fn iter(&self) -> PathIter {
+    pub fn iter(&self) -> PathIter<N> {
         PathIter::new(self)
     }
 }
 
 /// Iterator over `Paths`
-pub struct PathIter<'a> {
+pub struct PathIter<'a, N: 'a = i64> {
     max_candidate_no: usize,
-    paths: slice::Iter<'a, u32>,
+    paths: slice::Iter<'a, N>,
     to: usize,
     from: usize,
 }
 
-impl<'a> PathIter<'a> {
-    fn new(paths: &'a Paths) -> PathIter<'a> {
+impl<'a, N: Number + 'a> PathIter<'a, N> {
+    fn new(paths: &'a Paths<N>) -> PathIter<'a, N> {
         PathIter {
             max_candidate_no: paths.candidates - 1,
             paths: paths.paths.iter(),
@@ -107,10 +155,10 @@ impl<'a> PathIter<'a> {
     }
 }
 
-impl<'a> Iterator for PathIter<'a> {
-    type Item = (usize, usize, u32);
+impl<'a, N: Number + 'a> Iterator for PathIter<'a, N> {
+    type Item = (usize, usize, N);
 
-    fn next(&mut self) -> Option<(usize, usize, u32)> {
+    fn next(&mut self) -> Option<(usize, usize, N)> {
         if self.to == self.from {
             self.paths.next();
             self.increase_count();
@@ -129,10 +177,10 @@ impl<'a> Iterator for PathIter<'a> {
     }
 }
 
-impl<'a> ExactSizeIterator for PathIter<'a> {}
+impl<'a, N: Number + 'a> ExactSizeIterator for PathIter<'a, N> {}
 
 #[cfg(feature = "fused")]
-impl<'a> FusedIterator for PathIter<'a> {}
+impl<'a, N: Number + 'a> FusedIterator for PathIter<'a, N> {}
 
 #[cfg(test)]
 mod tests {
@@ -140,7 +188,7 @@ mod tests {
 
     #[test]
     fn path() {
-        let mut paths = Paths::new(3);
+        let mut paths = Paths::<i64>::new(3);
 
         assert_eq!(paths.path(1, 0), 0);
         assert_eq!(paths.path(2, 1), 0);
@@ -169,7 +217,7 @@ mod tests {
 
     #[test]
     fn exhausted_iterator() {
-        let paths = Paths::new(3);
+        let paths = Paths::<i64>::new(3);
         let mut iter = paths.iter().skip(5);
         assert!(iter.next().is_some());
         assert!(iter.next().is_none());
@@ -179,20 +227,20 @@ mod tests {
     #[test]
     #[should_panic(expected = "candidates have no preference to themselves")]
     fn path_to_self() {
-        let paths = Paths::new(100);
+        let paths = Paths::<i64>::new(100);
         paths.path(50, 50);
     }
 
     #[test]
     #[should_panic(expected = "candidates have no preference to themselves")]
     fn path_to_self_mut() {
-        let mut paths = Paths::new(100);
+        let mut paths = Paths::<i64>::new(100);
         paths.mut_path(0, 0);
     }
 
     #[test]
     fn iter_size_hint() {
-        let paths = Paths::new(20);
+        let paths = Paths::<i64>::new(20);
         let count = paths.iter().count();
         assert_eq!(count, paths.iter().size_hint().0);
         assert_eq!(Some(count), paths.iter().size_hint().1);
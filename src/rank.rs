@@ -44,7 +44,8 @@ pub trait Rank: Clone + Default + Ord {
 /// let rank2: SimpleRank = Some(5).into();
 /// assert!(rank1 == rank2);
 /// ```
-#[derive(Clone, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SimpleRank {
     rank: Option<u8>,
 }
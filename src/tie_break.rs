@@ -0,0 +1,53 @@
+//! Tie-breaking of the final candidate ranking
+//!
+//! The beatpath relation computed by the Schulze method is not necessarily a
+//! strict total order: two candidates can be tied (`paths.path(a, b) ==
+//! paths.path(b, a)`). [`TieBreak`] selects how `Election::result` resolves
+//! such ties when producing `ElectionResult::ranked_candidates`.
+//!
+//! [`TieBreak`]: enum.TieBreak.html
+
+use Candidate;
+
+/// Strategy used to resolve ties in the beatpath relation.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TieBreak {
+    /// Don't break ties; tied candidates end up in an arbitrary but stable order.
+    None,
+    /// Resolve ties using the Schulze "Tie-Breaking Ranking of Candidates" (TBRC).
+    ///
+    /// The tie-breaking order is primarily derived from the ballots themselves: for each pair
+    /// of candidates, the direction preferred by more ballots wins. `order` is a linear order
+    /// of all candidates (for instance taken from a designated or randomly drawn ballot) used
+    /// only to resolve what the ballots leave ambiguous, i.e. genuine pairwise ties or cycles.
+    /// `direction` selects whether ties are resolved from the top of the ranking down or from
+    /// the bottom up.
+    Tbrc {
+        /// Fallback order of all candidates, used when the ballots themselves don't resolve a
+        /// tie.
+        order: Vec<Candidate>,
+        /// Direction in which the tie-breaking ranking is built.
+        direction: Direction,
+    },
+}
+
+impl Default for TieBreak {
+    fn default() -> Self {
+        TieBreak::None
+    }
+}
+
+/// Direction in which a [`TieBreak::Tbrc`] ranking is resolved.
+///
+/// [`TieBreak::Tbrc`]: enum.TieBreak.html#variant.Tbrc
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+    /// Resolve ties starting with who is ranked highest: repeatedly rank the
+    /// potential-winner set among the remaining candidates.
+    Forwards,
+    /// Resolve ties starting with who is ranked lowest: repeatedly rank the
+    /// potential-loser set among the remaining candidates.
+    Backwards,
+}
@@ -11,7 +11,7 @@ pub fn rank(election: &mut Election, count: u32, ranks: &str) {
     }
 }
 
-pub fn extract_paths(result: &ElectionResult) -> Vec<(char, char, u32)> {
+pub fn extract_paths(result: &ElectionResult) -> Vec<(char, char, i64)> {
     let mut paths: Vec<_> = result
         .paths()
         .iter()
@@ -23,7 +23,7 @@ pub fn extract_paths(result: &ElectionResult) -> Vec<(char, char, u32)> {
     paths
 }
 
-pub fn assert_paths_eq(result: &ElectionResult, other: &[(char, char, u32)]) {
+pub fn assert_paths_eq(result: &ElectionResult, other: &[(char, char, i64)]) {
     let paths_is = extract_paths(result);
     assert_eq!(paths_is, other);
 }